@@ -12,32 +12,50 @@
 use codec::{Encode, Decode};
 use frame_support::{
     decl_module, decl_storage, decl_event, decl_error, ensure, RuntimeDebug,
-    dispatch::{DispatchResult},
-    traits::{Currency, Get},
+    dispatch::{DispatchError, DispatchResult},
+    traits::{BalanceStatus, Currency, EnsureOrigin, Get, ReservableCurrency},
+    weights::Weight,
 };
 
+use sp_runtime::traits::{IdentifyAccount, Verify};
 use sp_std::vec::Vec;
 use frame_system::ensure_signed;
 
-// type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
-// type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
-type UserInfoOf<T> = UserInfo<<T as frame_system::Config>::AccountId>;
+type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
+type UserInfoOf<T> = UserInfo<AccountIdOf<T>, BalanceOf<T>>;
+/// Upper bound on how many `PendingRegistrations` entries `on_initialize` inspects per block
+const MAX_EXPIRY_SWEEP_PER_BLOCK: u32 = 50;
 
 pub trait Config: frame_system::Config {
     type Event: From<Event<Self>> + Into<<Self as frame_system::Config>::Event>;
-    type Currency: Currency<Self::AccountId>;
+    /// Currency used to reserve an `AliasDeposit` against an account while it holds a phone alias
+    type Currency: ReservableCurrency<Self::AccountId>;
     type Transfer: Get<bool>;
+    /// Set in runtime configuration. The amount reserved from an account when it registers a
+    /// phone number alias, and unreserved when the alias is cleared
+    type AliasDeposit: Get<BalanceOf<Self>>;
     /// Set in runtime configuration. The max (inclusive) number of u8 characters allowed
     /// to be set to a phone number alias
 	type NumberMaxLength: Get<u32>;
 	/// Set in runtime configuration. The min (inclusive) number of u8 characters allowed
 	/// to be set to a phone number alias
 	type NumberMinLength: Get<u32>;
+	/// The origin allowed to manage the set of phone number attestation authorities.
+	type ForceOrigin: EnsureOrigin<Self::Origin>;
+	/// The signature type produced by an authority attesting to phone number ownership.
+	type OffchainSignature: Verify<Signer = Self::SigningPublicKey> + Decode + Encode;
+	/// The public key type recovered from an `OffchainSignature`, identifying the authority account.
+	type SigningPublicKey: IdentifyAccount<AccountId = Self::AccountId> + Decode + Encode;
+	/// How many blocks a `pre_approve`d registration remains claimable before it lapses.
+	type PendingExpiration: Get<Self::BlockNumber>;
+	/// The maximum number of seed-namespaced aliases a single account may register.
+	type MaxAliasesPerAccount: Get<u32>;
 }
 
 /// Custom struct type to hold user data within the substrate storage maps.
 #[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, Default)]
-pub struct UserInfo<AccountId>
+pub struct UserInfo<AccountId, Balance>
 {
     /// owner account
 	pub owner: AccountId,
@@ -47,6 +65,10 @@ pub struct UserInfo<AccountId>
     pub transferable: bool,
 	/// phone number alias to store
     pub phone: Phone,
+	/// amount reserved from `owner` as the `AliasDeposit` for this alias
+	pub deposit: Balance,
+	/// seed distinguishing this alias from an owner's other aliases, "" for the default alias
+	pub seed: Vec<u8>,
 }
 
 /// Custom struct type to hold phone number aliases
@@ -55,31 +77,87 @@ pub struct Phone(Vec<u8>);
 
 decl_storage! {
 	trait Store for Module<T: Config> as Pesa {
-		/// Storage map to look up a phone number alias by account ID
-		pub PhoneLookUp get(fn phone_look_up): map hasher(blake2_128_concat) T::AccountId => Option<UserInfoOf<T>>;
+		/// Storage map to look up a phone number alias by (account ID, seed) namespace. Both keys
+		/// use the `blake2_128_concat` hasher, which is reversible (it appends the unhashed key
+		/// after the hash), so the original `account`/`seed` can always be recovered from a raw
+		/// storage key even though the key itself is the double map's usual
+		/// `hash(account) ++ account ++ hash(seed) ++ seed` layout, not `blake2_128(account ++ seed)`.
+		pub PhoneLookUp get(fn phone_look_up): double_map hasher(blake2_128_concat) T::AccountId, hasher(blake2_128_concat) Vec<u8> => Option<UserInfoOf<T>>;
 		/// Storage map to look up account ID by phone number alias
 		pub AccountLookUp get(fn account_look_up): map hasher(blake2_128_concat) Phone => Option<UserInfoOf<T>>;
+		/// Index of the seeds an account has registered aliases under, used to enforce
+		/// `MaxAliasesPerAccount` and to locate an account's aliases without knowing their seeds
+		pub AccountAliasSeeds get(fn account_alias_seeds): map hasher(blake2_128_concat) T::AccountId => Vec<Vec<u8>>;
+		/// Accounts that have opted in via `allow_tranfer` to receive a one-time phone alias
+		/// handoff, independent of whether they already hold an alias of their own
+		pub TransferInbox get(fn transfer_inbox): map hasher(blake2_128_concat) T::AccountId => ();
+		/// Accounts authorized to attest off-chain to phone number ownership, keyed as a set
+		pub PhoneAuthorities get(fn phone_authorities): map hasher(blake2_128_concat) T::AccountId => ();
+		/// Phone numbers pre-approved by an authority for an account to `claim`, alongside the
+		/// last block at which the approval can still be claimed
+		pub PendingRegistrations get(fn pending_registrations): map hasher(blake2_128_concat) T::AccountId => Option<(Phone, T::BlockNumber)>;
+		/// Raw storage key cursor used to resume the bounded, per-block sweep of expired
+		/// `PendingRegistrations` entries across multiple blocks
+		pub PendingRegistrationsCursor get(fn pending_registrations_cursor): Option<Vec<u8>>;
+	}
+	add_extra_genesis {
+		/// Aliases to seed at chain launch, as `(owner, phone_bytes, public)` tuples. Bypasses
+		/// the `AliasDeposit` reservation and off-chain attestation required by `register`,
+		/// since genesis accounts hold no reserved balance yet.
+		config(phones): Vec<(T::AccountId, Vec<u8>, bool)>;
+		build(|config: &GenesisConfig<T>| {
+			for (owner, phone_bytes, public) in &config.phones {
+				let phone = Phone(phone_bytes.clone());
+				let phone_size = get_usize_safe(phone.0.len()).expect("genesis phone number too long to fit a u32 length");
+
+				assert!(phone_size >= T::NumberMinLength::get(), "genesis phone number shorter than NumberMinLength");
+				assert!(phone_size <= T::NumberMaxLength::get(), "genesis phone number longer than NumberMaxLength");
+				assert!(!<AccountLookUp<T>>::contains_key(&phone), "duplicate phone number in genesis config");
+				assert!(!<PhoneLookUp<T>>::contains_key(owner, &Vec::<u8>::new()), "duplicate owner in genesis config");
+
+				// genesis aliases are always seeded into the default (empty-seed) namespace
+				let user_info = UserInfo {
+					owner: owner.clone(),
+					public: *public,
+					transferable: false,
+					phone: phone.clone(),
+					deposit: Default::default(),
+					seed: Vec::new(),
+				};
+
+				<AccountLookUp<T>>::insert(&phone, &user_info);
+				<PhoneLookUp<T>>::insert(owner, Vec::new(), user_info);
+				<AccountAliasSeeds<T>>::append(owner, Vec::<u8>::new());
+			}
+		});
 	}
 }
 
 decl_event! {
 	pub enum Event<T> where
-		<T as frame_system::Config>::AccountId,
+		AccountId = <T as frame_system::Config>::AccountId,
+		Balance = BalanceOf<T>,
 	{
-		/// Phone alias transferred to second account
-		NumberTransfered(AccountId, AccountId),
-		/// Phone alias removed
-		NumberRemoved(),
+		/// Phone alias transferred to second account, its deposit repatriated \[from, to, deposit\]
+		NumberTransfered(AccountId, AccountId, Balance),
+		/// Phone alias removed, its deposit unreserved \[deposit\]
+		NumberRemoved(Balance),
 		/// Phone alias lookup success \[phone\]
 		LookUpSuccess(Vec<u8>),
 		/// Account found \[accountID\]
 		AccountFound(AccountId),
-		/// Account alias successfully registered
-		SuccessfullRegsitration(),
+		/// Account alias successfully registered, its deposit reserved \[deposit\]
+		SuccessfullRegsitration(Balance),
 		/// Account data returned \[accountID\] \[is_public\] \[is_transferable_to\] \[phone\]
 		AccountData(AccountId, bool, bool, Vec<u8>),
 		/// Allowed alias to transfer to this account,
 		TransferableSet(),
+		/// A phone number attestation authority was added \[authority\]
+		AuthorityAdded(AccountId),
+		/// A phone number attestation authority was removed \[authority\]
+		AuthorityRemoved(AccountId),
+		/// An authority pre-approved a phone number for an account to claim \[account\]
+		RegistrationPreApproved(AccountId),
 	}
 }
 
@@ -105,6 +183,14 @@ decl_error! {
 		NumberDoesNotExist,
 		/// Account does not exist in the system
 		AccountDoesNotExist,
+		/// The supplied off-chain attestation failed to verify against a known authority
+		InvalidAttestation,
+		/// The pre-approved registration deadline has passed
+		RegistrationExpired,
+		/// Not enough free balance to reserve the `AliasDeposit`
+		InsufficientBalance,
+		/// Account has already registered `MaxAliasesPerAccount` aliases
+		TooManyAliases,
 	}
 }
 
@@ -113,42 +199,131 @@ decl_module! {
 		type Error = Error<T>;
 		fn deposit_event() = default;
 
-		/// Register a phone number alias to the current Substrate account. Excessively long or short input will result in an error.
-		/// PhoneNumber / Number Public for Lookup / Is Public
+		/// Sweep expired `PendingRegistrations` entries so stale pre-approvals don't accumulate.
+		/// Bounded per block via `PendingRegistrationsCursor` so a large backlog is swept
+		/// incrementally across many blocks rather than all at once.
+		fn on_initialize(_n: T::BlockNumber) -> Weight {
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut iter = match <PendingRegistrationsCursor>::get() {
+				Some(last_raw_key) => <PendingRegistrations<T>>::iter_from(last_raw_key),
+				None => <PendingRegistrations<T>>::iter(),
+			};
+
+			// 1 read for the cursor itself, plus 1 read per entry inspected below
+			let mut reads: Weight = 1;
+			let mut writes: Weight = 0;
+
+			for _ in 0..MAX_EXPIRY_SWEEP_PER_BLOCK {
+				match iter.next() {
+					Some((who, (_phone, deadline))) => {
+						reads += 1;
+						if now > deadline {
+							<PendingRegistrations<T>>::remove(&who);
+							writes += 1;
+						}
+					},
+					None => {
+						<PendingRegistrationsCursor>::kill();
+						writes += 1;
+						return T::DbWeight::get().reads_writes(reads, writes);
+					},
+				}
+			}
+
+			<PendingRegistrationsCursor>::put(iter.last_raw_key().to_vec());
+			writes += 1;
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		/// Register a phone number alias to the current Substrate account, proven by an off-chain
+		/// attestation from a known authority rather than a bare claim. The signature must be
+		/// produced by `authority_public_key` over the SCALE-encoded `(phone_number, account)`
+		/// tuple. Excessively long or short input will result in an error.
+		/// PhoneNumber / Number Public for Lookup / Is New Account / Seed namespace (empty for the default alias)
 		#[weight = 1000]
-		pub fn register(origin, phone_number: Phone, public: bool, new_account: bool) -> DispatchResult
+		pub fn register(
+			origin,
+			phone_number: Phone,
+			public: bool,
+			new_account: bool,
+			seed: Option<Vec<u8>>,
+			authority_public_key: T::SigningPublicKey,
+			signature: T::OffchainSignature,
+		) -> DispatchResult
 		{
 			let account = ensure_signed(origin)?;
-			let phone_size = get_usize_safe(phone_number.0.len()).ok_or_else(|| Error::<T>::NumberOverflow)?;
 
+			let payload = (phone_number.0.clone(), account.clone()).encode();
+			ensure!(signature.verify(&payload[..], &authority_public_key), Error::<T>::InvalidAttestation);
+
+			let authority = authority_public_key.into_account();
+			ensure!(<PhoneAuthorities<T>>::contains_key(&authority), Error::<T>::InvalidAttestation);
+
+			let deposit = Self::do_register(account, phone_number, public, new_account, seed.unwrap_or_default())?;
+
+			Self::deposit_event(RawEvent::SuccessfullRegsitration(deposit));
+			Ok(())
+		}
+
+		/// Authorize an account to attest off-chain to phone number ownership
+		#[weight = 1000]
+		pub fn add_authority(origin, authority: T::AccountId) -> DispatchResult
+		{
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			<PhoneAuthorities<T>>::insert(&authority, ());
+			Self::deposit_event(RawEvent::AuthorityAdded(authority));
+			Ok(())
+		}
+
+		/// Revoke an account's authorization to attest off-chain to phone number ownership
+		#[weight = 1000]
+		pub fn remove_authority(origin, authority: T::AccountId) -> DispatchResult
+		{
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			<PhoneAuthorities<T>>::remove(&authority);
+			Self::deposit_event(RawEvent::AuthorityRemoved(authority));
+			Ok(())
+		}
+
+		/// Pre-approve a phone number for `who` to claim later, rather than registering it
+		/// directly. Callable only by a known phone authority. The approval lapses after
+		/// `PendingExpiration` blocks if `who` never calls `claim`.
+		#[weight = 1000]
+		pub fn pre_approve(origin, who: T::AccountId, phone: Phone) -> DispatchResult
+		{
+			let authority = ensure_signed(origin)?;
+			ensure!(<PhoneAuthorities<T>>::contains_key(&authority), Error::<T>::InvalidAttestation);
+
+			let phone_size = get_usize_safe(phone.0.len()).ok_or_else(|| Error::<T>::NumberOverflow)?;
 			ensure!(phone_size >= T::NumberMinLength::get(), Error::<T>::NumberTooShort);
 			ensure!(phone_size <= T::NumberMaxLength::get(), Error::<T>::NumberTooLong);
 
-		//	new accounts should not already be in storage
+			let deadline = frame_system::Pallet::<T>::block_number() + T::PendingExpiration::get();
+			<PendingRegistrations<T>>::insert(&who, (phone, deadline));
 
-			if new_account{
-				ensure!(!<PhoneLookUp<T>>::contains_key(&account), Error::<T>::NumberAlreadyExists);
-				ensure!(!<AccountLookUp<T>>::contains_key(&phone_number), Error::<T>::NumberAlreadyExists);
-			}
+			Self::deposit_event(RawEvent::RegistrationPreApproved(who));
+			Ok(())
+		}
 
-			// existing accounts should already be in storage
-			if !new_account{
-				ensure!(<PhoneLookUp<T>>::contains_key(&account), Error::<T>::NumberDoesNotExist);
-				ensure!(<AccountLookUp<T>>::contains_key(&phone_number), Error::<T>::NumberDoesNotExist);
-			}
+		/// Claim a phone number that an authority has `pre_approve`d for the caller, so long as
+		/// the approval has not yet expired.
+		#[weight = 1000]
+		pub fn claim(origin) -> DispatchResult
+		{
+			let account = ensure_signed(origin)?;
+			let (phone, deadline) = <PendingRegistrations<T>>::get(&account).ok_or(Error::<T>::LookupFailure)?;
 
-			let user_info = UserInfo
-			{
-				owner: account.clone(),
-				public: public,
-				transferable: false,
-				phone: phone_number.clone(),
-			};
+			if frame_system::Pallet::<T>::block_number() > deadline {
+				<PendingRegistrations<T>>::remove(&account);
+				return Err(Error::<T>::RegistrationExpired.into());
+			}
 
-			<AccountLookUp<T>>::insert(phone_number, &user_info);
-			<PhoneLookUp<T>>::insert(account, user_info);
+			let deposit = Self::do_register(account.clone(), phone, false, true, Vec::new())?;
+			<PendingRegistrations<T>>::remove(&account);
 
-			Self::deposit_event(RawEvent::SuccessfullRegsitration());
+			Self::deposit_event(RawEvent::SuccessfullRegsitration(deposit));
 			Ok(())
 		}
 
@@ -175,7 +350,8 @@ decl_module! {
 		{
 			let _account = ensure_signed(origin)?;
 
-			let user = Self::phone_look_up(check_account).ok_or(Error::<T>::LookupFailure)?;
+			// looks up the default (empty-seed) alias; use `account_data` for a seed-namespaced one
+			let user = Self::phone_look_up(check_account, Vec::new()).ok_or(Error::<T>::LookupFailure)?;
 			ensure!(user.public, Error::<T>::NumberNotPublic);
 
 			Self::deposit_event(RawEvent::LookUpSuccess(user.phone.0));
@@ -185,10 +361,10 @@ decl_module! {
 
 		/// Fetch own account data
 		#[weight = 1000]
-		pub fn account_data(origin) -> DispatchResult
+		pub fn account_data(origin, seed: Option<Vec<u8>>) -> DispatchResult
 		{
 			let account = ensure_signed(origin)?;
-			let user = Self::phone_look_up(&account).ok_or(Error::<T>::LookupFailure)?;
+			let user = Self::phone_look_up(&account, seed.unwrap_or_default()).ok_or(Error::<T>::LookupFailure)?;
 
 			// Account ID, public, transferable, phone
 			Self::deposit_event(RawEvent::AccountData(account, user.public, user.transferable, user.phone.0));
@@ -196,75 +372,145 @@ decl_module! {
 
 		}
 
-		///Enable permissions for a one time transfer of a phone number alias to an origin account
+		/// Opt in via `TransferInbox` to receive a one-time phone alias handoff, whether or not
+		/// the caller already holds an alias of its own
 		#[weight = 1000]
 		pub fn allow_tranfer(origin) -> DispatchResult
 		{
 			let account = ensure_signed(origin)?;
 
-			ensure!(<PhoneLookUp<T>>::contains_key(&account), Error::<T>::AccountDoesNotExist);
-			let mut user = Self::phone_look_up(&account).ok_or(Error::<T>::LookupFailure)?;
-			ensure!(<AccountLookUp<T>>::contains_key(&user.phone), Error::<T>::NumberDoesNotExist);
-
-			user.transferable = true;
-
-			PhoneLookUp::<T>::insert(account, &user);
-			AccountLookUp::<T>::insert(&user.phone, &user);
+			// consent to receive lives in `TransferInbox`, not on an existing alias record, so a
+			// fresh account with no alias of its own can still opt in to receive one
+			<TransferInbox<T>>::insert(&account, ());
 
 			Self::deposit_event(RawEvent::TransferableSet());
 
 			Ok(())
-
-			// Dev note: Tried using mutate exists, but seems better practice to check first if
-			// exists in both databases and then fail fast, rather than mutate and change in one,
-			// but possibly fail in the second
-
-			// PhoneLookUp::<T>::try_mutate_exists(account.clone(), |user_info| -> DispatchResult
-			// {
-			// 		let mut user_info = user_info.take().ok_or(Error::<T>::IncorrectInformation)?;
-			// 		user_info.transferable = true;
-			// 		PhoneLookUp::<T>::insert(&account, user_info);
-			// 		Ok(())
-			// })?
 		}
 
 		/// Transfer phone number alias to a second account. The second account (transfer_account) must have
-		/// allow transferred enabled first. Once the transfer has occurred, the allow transfer toggle will
-		/// be disabled again
+		/// called `allow_tranfer` first to appear in `TransferInbox`. Consent to receive is not consent to
+		/// lose a number the destination already holds, so the transfer is rejected outright if the
+		/// destination already has a default-seed alias of its own. Once the transfer has occurred, the
+		/// `TransferInbox` entry is consumed so it is genuinely one-time
 		#[weight = 1000]
 		pub fn tranfer(origin, tranfer_account: T::AccountId) -> DispatchResult
 		{
-			let _account = ensure_signed(origin)?;
+			let account = ensure_signed(origin)?;
+
+			// operates on the default (empty-seed) alias on the origin side; all reads and
+			// existence checks happen before any writes, so a failure here leaves both maps
+			// untouched
+			let origin_info = Self::phone_look_up(&account, Vec::new()).ok_or(Error::<T>::AccountDoesNotExist)?;
+			ensure!(<AccountLookUp<T>>::contains_key(&origin_info.phone), Error::<T>::NumberDoesNotExist);
+			ensure!(<TransferInbox<T>>::contains_key(&tranfer_account), Error::<T>::InvalidTransfer);
+
+			// the destination opted in to receive, not to losing a number it already owns, so a
+			// pre-existing default-seed alias on the destination blocks the transfer entirely
+			ensure!(!<PhoneLookUp<T>>::contains_key(&tranfer_account, Vec::new()), Error::<T>::NumberAlreadyExists);
+
+			T::Currency::repatriate_reserved(&account, &tranfer_account, origin_info.deposit, BalanceStatus::Reserved)?;
+
+			let mut new_info = origin_info;
+			new_info.owner = tranfer_account.clone();
+			new_info.transferable = false;
 
-			//todo 1
-			//get ops phone number
-			// get transer accounts user info
-			// check that num can be transfered to
-			// add numbers to new account
-			// remove numbers from old account
+			<AccountLookUp<T>>::insert(&new_info.phone, &new_info);
+			<PhoneLookUp<T>>::remove(&account, Vec::new());
+			<PhoneLookUp<T>>::insert(&tranfer_account, Vec::new(), &new_info);
+			<AccountAliasSeeds<T>>::mutate(&account, |seeds| seeds.retain(|s| !s.is_empty()));
+			<AccountAliasSeeds<T>>::append(&tranfer_account, Vec::<u8>::new());
+			<TransferInbox<T>>::remove(&tranfer_account);
 
+			Self::deposit_event(RawEvent::NumberTransfered(account, tranfer_account, new_info.deposit));
 			Ok(())
 		}
 
 		/// Clear number alias data from storage.
 		#[weight = 1000]
-		pub fn clear_data(origin) -> DispatchResult
+		pub fn clear_data(origin, seed: Option<Vec<u8>>) -> DispatchResult
 		{
 			let account = ensure_signed(origin)?;
+			let seed = seed.unwrap_or_default();
 
-			ensure!(<PhoneLookUp<T>>::contains_key(&account), Error::<T>::IncorrectInformation);
-			let user = Self::phone_look_up(&account).ok_or(Error::<T>::LookupFailure)?;
+			ensure!(<PhoneLookUp<T>>::contains_key(&account, &seed), Error::<T>::IncorrectInformation);
+			let user = Self::phone_look_up(&account, &seed).ok_or(Error::<T>::LookupFailure)?;
 			ensure!(<AccountLookUp<T>>::contains_key(&user.phone), Error::<T>::IncorrectInformation);
 
+			T::Currency::unreserve(&account, user.deposit);
+
 			<AccountLookUp<T>>::remove(&user.phone);
-			<PhoneLookUp<T>>::remove(account);
+			<PhoneLookUp<T>>::remove(&account, &seed);
+			<AccountAliasSeeds<T>>::mutate(&account, |seeds| seeds.retain(|s| s != &seed));
 
-			Self::deposit_event(RawEvent::NumberRemoved());
+			Self::deposit_event(RawEvent::NumberRemoved(user.deposit));
 			Ok(())
 		}
 	}
 }
 
+impl<T: Config> Module<T> {
+	/// Validate and insert a phone alias into the `(account, seed)` namespace, returning the
+	/// deposit reserved for it. Shared by `register` and `claim`. New
+	/// registrations reserve a fresh `AliasDeposit` and count against `MaxAliasesPerAccount`;
+	/// re-registrations of an existing account/phone/seed pair (`new_account == false`) carry
+	/// over the deposit already held.
+	fn do_register(account: T::AccountId, phone_number: Phone, public: bool, new_account: bool, seed: Vec<u8>) -> Result<BalanceOf<T>, DispatchError> {
+		let phone_size = get_usize_safe(phone_number.0.len()).ok_or_else(|| Error::<T>::NumberOverflow)?;
+
+		ensure!(phone_size >= T::NumberMinLength::get(), Error::<T>::NumberTooShort);
+		ensure!(phone_size <= T::NumberMaxLength::get(), Error::<T>::NumberTooLong);
+
+		//	new accounts should not already be in storage
+
+		if new_account{
+			ensure!(!<PhoneLookUp<T>>::contains_key(&account, &seed), Error::<T>::NumberAlreadyExists);
+			ensure!(!<AccountLookUp<T>>::contains_key(&phone_number), Error::<T>::NumberAlreadyExists);
+			ensure!(
+				(Self::account_alias_seeds(&account).len() as u32) < T::MaxAliasesPerAccount::get(),
+				Error::<T>::TooManyAliases
+			);
+		}
+
+		// existing accounts should already be in storage
+		if !new_account{
+			ensure!(<PhoneLookUp<T>>::contains_key(&account, &seed), Error::<T>::NumberDoesNotExist);
+			ensure!(<AccountLookUp<T>>::contains_key(&phone_number), Error::<T>::NumberDoesNotExist);
+			ensure!(
+				Self::phone_look_up(&account, &seed).map_or(false, |info| info.phone == phone_number),
+				Error::<T>::IncorrectInformation
+			);
+		}
+
+		let deposit = if new_account {
+			let deposit = T::AliasDeposit::get();
+			T::Currency::reserve(&account, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+			deposit
+		} else {
+			Self::phone_look_up(&account, &seed).map(|info| info.deposit).unwrap_or_default()
+		};
+
+		let user_info = UserInfo
+		{
+			owner: account.clone(),
+			public: public,
+			transferable: false,
+			phone: phone_number.clone(),
+			deposit,
+			seed: seed.clone(),
+		};
+
+		<AccountLookUp<T>>::insert(phone_number, &user_info);
+		<PhoneLookUp<T>>::insert(&account, &seed, user_info);
+
+		if new_account {
+			<AccountAliasSeeds<T>>::append(&account, seed);
+		}
+
+		Ok(deposit)
+	}
+}
+
 /// Covert uSize to u32 without using the standard library
 fn get_usize_safe(v: usize) -> Option<u32> {
     if v > u32::MAX as usize {